@@ -0,0 +1,835 @@
+//! Zinc-LHA: a streaming, Merkle–Damgård hash function.
+//!
+//! The [`ZincLha`] engine absorbs input incrementally via [`ZincLha::update`]
+//! and produces an `N`-byte digest (64 bytes by default) via
+//! [`ZincLha::finalize`]. Internally the message is buffered into 64-byte
+//! blocks; each full block is folded into the running state with repeated
+//! [`round`]s, and the final (possibly partial) block is length-padded
+//! before one last absorption and an [`end_mix`] pass.
+
+// --- Helper Functions ---
+
+/// Performs a left rotation on a byte by a given number of bits.
+///
+/// # Parameters
+/// - `val`: The byte to rotate.
+/// - `bits`: Number of bits to rotate left.
+///
+/// # Returns
+/// The rotated byte.
+fn rotl(val: u8, bits: u32) -> u8 {
+    val.rotate_left(bits)
+}
+
+/// Performs a lookup in a substitution box (S-box).
+///
+/// # Parameters
+/// - `sbox`: Reference to a 256-element array representing the S-box.
+/// - `val`: The input byte to transform via the S-box.
+///
+/// # Returns
+/// The transformed byte from the S-box.
+fn sbox_lookup(sbox: &[u8; 256], val: u8) -> u8 {
+    sbox[val as usize]
+}
+
+// --- S-Box Initialization ---
+
+/// Initializes a 256-element S-box using a key-dependent algorithm.
+///
+/// The initialization uses a two-pass mixing process:
+/// 1. First pass: mixes S-box entries with input bytes and pseudo-random rotations.
+/// 2. Second pass: further scrambles the S-box using previous S-box values.
+///
+/// # Parameters
+/// - `bytes`: Key data to seed the S-box.
+///
+/// # Returns
+/// A fully initialized 256-byte S-box.
+fn init_sbox(bytes: &[u8]) -> [u8; 256] {
+    let len = bytes.len();
+    let mut sbox = [0u8; 256];
+
+    // Initialize S-box sequentially
+    for (i, entry) in sbox.iter_mut().enumerate() {
+        *entry = i as u8;
+    }
+
+    let mut seed = bytes[0].wrapping_add(bytes[len - 1]);
+
+    // --- First Mixing Pass ---
+    for i in (1..256).rev() {
+        seed = seed.wrapping_add(bytes[i % len])
+            ^ sbox[i]
+            ^ sbox[(i + 7) % 256]
+            ^ seed.rotate_left((i % 5) as u32);
+        let j = (seed as usize ^ i) % 256;
+        sbox[i] ^= bytes[i % len];
+        sbox[j] ^= bytes[(i + 1) % len];
+        sbox.swap(i, j);
+    }
+
+    // --- Second Mixing Pass ---
+    for i in (1..256).rev() {
+        seed ^= sbox[(i * 3) % 256];
+        let j = (seed as usize ^ i) % 256;
+        sbox[i] ^= bytes[(i + 16) % len];
+        sbox[j] ^= bytes[(i + (i.wrapping_mul(11) ^ i)) % len];
+        sbox.swap(i, j);
+    }
+
+    sbox
+}
+
+// --- Cross-Byte Diffusion ---
+
+/// Diffuses the state by XOR-ing each byte with the one 3 positions ahead
+/// (wrapping), i.e. `state[i] ^= state[(i + 3) % 64]`.
+///
+/// This single elementwise sweep is run once per [`round`]. With the
+/// `simd` feature it runs as vectorized XORs over 16-byte lanes (falling
+/// back to scalar for the bytes that don't fill a whole lane and for the
+/// final, wrap-affected bytes); without it, it runs
+/// as the equivalent scalar loop. Both paths produce bit-identical output.
+fn diffuse(state: &mut [u8; 64]) {
+    #[cfg(feature = "simd")]
+    {
+        diffuse_simd(state);
+    }
+    #[cfg(not(feature = "simd"))]
+    {
+        diffuse_scalar(state);
+    }
+}
+
+/// Scalar implementation of [`diffuse`].
+///
+/// Indices `0..61` read `state[i + 3]`, which the sequential loop hasn't
+/// reached yet, so those reads see the pre-call values. Indices `61..64`
+/// wrap around to `0..3`, which the same loop already updated, so those
+/// three reads see already-diffused values. `diffuse_simd` below
+/// reconstructs this split explicitly to stay vectorizable.
+#[cfg_attr(feature = "simd", allow(dead_code))]
+fn diffuse_scalar(state: &mut [u8; 64]) {
+    for i in 0..state.len() {
+        state[i] ^= state[(i + 3) % state.len()];
+    }
+}
+
+/// SIMD implementation of [`diffuse`] using 16-byte lanes, with a scalar
+/// remainder and wrap-around tail.
+///
+/// Bytes `0..61` never wrap (`i + 3 < 64`), so `state[i] = old[i] ^
+/// old[i + 3]` there is a pure, lane-parallel XOR of two offset slices of
+/// the pre-call state — vectorized here over `u8x16` chunks, with a scalar
+/// remainder for the tail that doesn't fill a whole lane. Bytes `61..64`
+/// wrap onto indices `0..3`, which the bulk step already overwrote, so they
+/// are finished with three scalar XORs against the new values, exactly
+/// reproducing the scalar loop's sequential read-after-write.
+#[cfg(feature = "simd")]
+fn diffuse_simd(state: &mut [u8; 64]) {
+    use wide::u8x16;
+
+    let old = *state;
+
+    let mut i = 0;
+    while i + 16 <= 61 {
+        let a = u8x16::from(<[u8; 16]>::try_from(&old[i..i + 16]).unwrap());
+        let b = u8x16::from(<[u8; 16]>::try_from(&old[i + 3..i + 19]).unwrap());
+        state[i..i + 16].copy_from_slice((a ^ b).as_array_ref());
+        i += 16;
+    }
+    while i < 61 {
+        state[i] = old[i] ^ old[i + 3];
+        i += 1;
+    }
+
+    state[61] ^= state[0];
+    state[62] ^= state[1];
+    state[63] ^= state[2];
+}
+
+// --- Core Hash Round Function ---
+
+/// Performs a single round of the Zinc-LHA hash function on a 64-byte state.
+///
+/// Each byte in the state undergoes multiple transformations:
+/// - XOR with rotated state bytes
+/// - Multiplication and addition
+/// - XOR with rotated input block bytes
+/// - Rotations based on S-box and block values
+/// - Substitution via S-box lookup
+/// - Additional mixing and "salt" injection based on the block bytes
+///
+/// # Parameters
+/// - `state`: Mutable reference to the 64-byte hash state.
+/// - `block`: Reference to the current 64-byte message block being absorbed.
+/// - `sbox`: Reference to the initialized 256-byte S-box.
+/// - `key`: Key bytes seeding and evolving the salt schedule (the engine's
+///   default key when unkeyed). The key never appears in `block`, so it
+///   drives the salt-injection loop without being absorbed as message data.
+fn round(state: &mut [u8; 64], block: &[u8; 64], sbox: &[u8; 256], key: &[u8]) {
+    let len = block.len();
+
+    // Main byte-wise transformations
+    for i in 0..state.len() {
+        let j = state.len() - 1 - i;
+        state[i] ^= rotl(state[j], 7);
+        state[i] = state[i].wrapping_mul(0x9E).wrapping_add(state[(i + 1) % 8]);
+        state[i] ^= rotl(block[(i + 4) % len], 3);
+
+        let idx = ((i % len) ^ (len.wrapping_mul(state[i % len] as usize))) % 64;
+        state[i] = rotl(state[i], (block[idx].wrapping_add(sbox[idx]) % 8) as u32);
+        state[i] ^= rotl(state[(i + 7) % state.len()], 3);
+        state[i] = sbox_lookup(sbox, state[i]);
+    }
+
+    // Simple cross-byte mixing
+    diffuse(state);
+
+    // Salt-based mixing seeded from the key and evolved by the key, with the
+    // block XORed in so the schedule still depends on the message. Evolving
+    // by rotate-then-add (rather than multiplying the salt by a block byte)
+    // keeps the salt moving even over an all-zero block: a multiplicative
+    // step has an absorbing zero (`salt.wrapping_mul(0)` freezes it), while
+    // `rotl(salt, 1).wrapping_add(key_byte)` moves a zero salt to the key
+    // byte, so it only stays at zero where the key byte is zero.
+    let mut salt = key[0]
+        .wrapping_add(key[key.len() - 1].wrapping_mul(sbox[(key.len() - 1) % 256]))
+        .rotate_left(3);
+
+    for i in 0..state.len() {
+        let k = key[i % key.len()];
+        salt = rotl(salt, 1).wrapping_add(k) ^ block[i.wrapping_add(len - 1) % len];
+        state[i] ^= rotl(salt, (i % 8) as u32);
+    }
+
+    // Final intra-round mixing
+    for i in 0..state.len() {
+        state[i] ^= state[(i + 3) % state.len()];
+        state[i] = state[i]
+            .wrapping_add(state[(i + 5) % state.len()])
+            .wrapping_mul(state[(i + 3) % state.len()])
+            .rotate_left(5);
+
+        let idx = ((i % len) ^ (len.wrapping_mul(state[(i + 3) % len] as usize))) % 64;
+        state[i] = rotl(state[i], (block[idx] % 8) as u32 + 1);
+        state[i] ^= rotl(state[(i + 4) % state.len()], 4);
+    }
+}
+
+// --- End-of-Hash Mixing ---
+
+/// Performs a final mixing of the hash state with the input block and S-box.
+///
+/// This ensures that each byte of the state is influenced by the input block and
+/// S-box in a non-linear manner, improving diffusion.
+///
+/// # Parameters
+/// - `state`: Mutable reference to the 64-byte hash state.
+/// - `block`: Reference to the input block.
+/// - `sbox`: Reference to the initialized S-box.
+fn end_mix(state: &mut [u8; 64], block: &[u8; 64], sbox: &[u8; 256]) {
+    for (s, b) in state.iter_mut().zip(block.iter()) {
+        *s ^= *b;
+        *s = rotl(*s, sbox[*s as usize] as u32);
+        *s = s.wrapping_add(*b).wrapping_mul(3) ^ *b;
+    }
+}
+
+/// Default key used to seed the S-box and salt schedule when no explicit
+/// key is supplied via [`ZincLha::with_key`].
+const DEFAULT_KEY: &[u8] = b"zinc-lha-default-key-v1";
+
+/// Minimum number of rounds folded into every absorbed block.
+const BASE_ROUNDS: usize = 10_000;
+
+// --- Streaming Engine ---
+
+/// A streaming Zinc-LHA hash engine producing an `N`-byte digest.
+///
+/// `N` defaults to 64 (the internal compression width) and may otherwise be
+/// 16, 32, or 128; see [`finalize`](ZincLha::finalize) for how shorter and
+/// longer digests are derived from the 64-byte state. Input is absorbed in
+/// 64-byte blocks via [`update`](ZincLha::update); the final block is
+/// length-padded Merkle–Damgård style and mixed in by `finalize`, so the
+/// digest depends on the entire message and its length rather than just the
+/// first 64 bytes.
+#[derive(Clone)]
+pub struct ZincLha<const N: usize = 64> {
+    state: [u8; 64],
+    sbox: [u8; 256],
+    key: Vec<u8>,
+    rounds_per_block: usize,
+    buffer: [u8; 64],
+    buffer_len: usize,
+    total_len: u64,
+}
+
+impl<const N: usize> ZincLha<N> {
+    /// Creates a new engine seeded with the default (unkeyed) schedule.
+    pub fn new() -> Self {
+        Self::with_key(DEFAULT_KEY)
+    }
+
+    /// Creates a new engine keyed with `key` (SipHash-style keyed hashing).
+    ///
+    /// The key seeds `init_sbox` and the per-round salt schedule, while the
+    /// message absorbed via [`update`](ZincLha::update) is folded into the
+    /// state separately and never itself touches the S-box or salt seed.
+    /// This yields a keyed fingerprint/MAC: changing the key fully
+    /// re-derives the S-box and salt schedule, so the same message produces
+    /// different digests under different keys.
+    ///
+    /// # Panics
+    /// Panics if `N` is not one of 16, 32, 64, or 128.
+    pub fn with_key(key: &[u8]) -> Self {
+        assert!(
+            matches!(N, 16 | 32 | 64 | 128),
+            "ZincLha only supports 16-, 32-, 64-, or 128-byte digests"
+        );
+
+        let key: Vec<u8> = if key.is_empty() { vec![0u8] } else { key.to_vec() };
+        let sbox = init_sbox(&key);
+        let extra_rounds = (key[0].wrapping_add(key[key.len() - 1]) % 255) as usize % 1000;
+
+        ZincLha {
+            state: [0u8; 64],
+            sbox,
+            key,
+            rounds_per_block: BASE_ROUNDS + extra_rounds,
+            buffer: [0u8; 64],
+            buffer_len: 0,
+            total_len: 0,
+        }
+    }
+
+    /// Absorbs more input into the engine.
+    ///
+    /// Data is buffered into 64-byte blocks; each full block is folded into
+    /// the state immediately, so arbitrarily large input can be streamed in
+    /// without holding the whole message in memory.
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.total_len = self.total_len.wrapping_add(data.len() as u64);
+
+        while !data.is_empty() {
+            let space = 64 - self.buffer_len;
+            let take = space.min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+
+            if self.buffer_len == 64 {
+                self.absorb_block();
+                self.buffer_len = 0;
+            }
+        }
+    }
+
+    /// Finalizes the hash, consuming the engine, and returns the `N`-byte digest.
+    ///
+    /// The trailing block is padded with a single `0x80` byte, zero bytes,
+    /// and the total message bit-length as a big-endian `u64` in the last 8
+    /// bytes (spilling into an extra all-zero block first if there isn't
+    /// room), then that block is absorbed like any other (`rounds_per_block`
+    /// rounds) and an [`end_mix`] pass is applied to produce the 64-byte
+    /// compression state.
+    ///
+    /// That state is then shaped to `N` bytes: for `N == 64` it is returned
+    /// as-is; for `N < 64` it is folded down by repeatedly XOR-ing disjoint
+    /// halves; for `N > 64` it is replaced by fresh output blocks from
+    /// additional domain-separated `round`/`end_mix` passes, so the 64-byte
+    /// digest is not a prefix of the 128-byte one. The folds also keep the
+    /// 32-byte digest from being a prefix of the 64-byte one, but no length
+    /// tag is mixed in for `N <= 64`: the 16-byte digest is the fold of the
+    /// 32-byte digest, which is the fold of the 64-byte digest.
+    pub fn finalize(mut self) -> [u8; N] {
+        let bit_len = self.total_len.wrapping_mul(8);
+        let start = self.buffer_len;
+
+        if start >= 56 {
+            self.buffer[start] = 0x80;
+            for b in &mut self.buffer[start + 1..64] {
+                *b = 0;
+            }
+            self.absorb_block();
+            self.buffer = [0u8; 64];
+        } else {
+            self.buffer[start] = 0x80;
+            for b in &mut self.buffer[start + 1..56] {
+                *b = 0;
+            }
+        }
+
+        self.buffer[56..64].copy_from_slice(&bit_len.to_be_bytes());
+        self.absorb_block();
+
+        end_mix(&mut self.state, &self.buffer, &self.sbox);
+
+        let output = extract_output::<N>(
+            &self.state,
+            &self.buffer,
+            &self.sbox,
+            &self.key,
+            self.rounds_per_block,
+        );
+        output.try_into().unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Runs `rounds_per_block` rounds of the compression function over the
+    /// current block, chaining the state Merkle–Damgård style.
+    fn absorb_block(&mut self) {
+        for _ in 0..self.rounds_per_block {
+            round(&mut self.state, &self.buffer, &self.sbox, &self.key);
+        }
+    }
+}
+
+impl<const N: usize> Default for ZincLha<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Shapes the 64-byte compression state into an `N`-byte digest.
+///
+/// For `N <= 64` the state is repeatedly folded in half (XOR of disjoint
+/// halves) until it reaches `N` bytes. For `N > 64` every 64-byte output
+/// block — including the first, which would otherwise just be the raw
+/// `state` and hence a prefix of the `N == 64` digest — is produced by
+/// running `rounds` more rounds (and an `end_mix` pass) over a copy of the
+/// state, using the final block XORed with an incrementing counter so each
+/// block is domain-separated from the others and from the `N <= 64` digests.
+fn extract_output<const N: usize>(
+    state: &[u8; 64],
+    block: &[u8; 64],
+    sbox: &[u8; 256],
+    key: &[u8],
+    rounds: usize,
+) -> Vec<u8> {
+    if N <= 64 {
+        let mut folded = state.to_vec();
+        while folded.len() > N {
+            let half = folded.len() / 2;
+            let (lo, hi) = folded.split_at(half);
+            folded = lo.iter().zip(hi.iter()).map(|(a, b)| a ^ b).collect();
+        }
+        folded
+    } else {
+        let mut output = Vec::with_capacity(N);
+        let mut extra_state = *state;
+        let mut counter: u8 = 1;
+
+        while output.len() < N {
+            let mut domain_block = *block;
+            domain_block[63] ^= counter;
+            counter = counter.wrapping_add(1);
+
+            for _ in 0..rounds {
+                round(&mut extra_state, &domain_block, sbox, key);
+            }
+            end_mix(&mut extra_state, &domain_block, sbox);
+
+            let take = (N - output.len()).min(64);
+            output.extend_from_slice(&extra_state[..take]);
+        }
+        output
+    }
+}
+
+// --- std::hash::Hasher Integration ---
+
+impl core::hash::Hasher for ZincLha {
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    /// Folds the 64-byte digest down to a `u64`.
+    ///
+    /// Finalization is non-destructive here (the engine is cloned first) so
+    /// `finish` can be called repeatedly, as `Hasher` requires.
+    fn finish(&self) -> u64 {
+        fold_to_u64(&self.clone().finalize())
+    }
+}
+
+/// A 128-bit digest, folded down from the full 64-byte state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hash128(pub u128);
+
+/// Folds a 64-byte digest down to a `u64` by mixing in its eight 8-byte lanes.
+///
+/// Lanes are always read big-endian, so the result is identical on
+/// big- and little-endian targets regardless of the host's native byte
+/// order. Each lane is folded in through [`mix`] rather than a plain XOR,
+/// so the result avalanches even when lanes share low-order bits.
+fn fold_to_u64(digest: &[u8; 64]) -> u64 {
+    digest
+        .chunks_exact(8)
+        .map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap()))
+        .fold(0u64, |acc, lane| mix(acc ^ lane))
+}
+
+// --- Stateless Avalanche Finalizer ---
+
+/// An mx3-style stateless 64-bit avalanche finalizer.
+///
+/// Xor-shifts right and multiplies by an odd constant three times over, then
+/// applies one last xor-shift, so that every
+/// output bit depends on every input bit. Used both by [`fold_to_u64`] (and
+/// hence [`ZincLha::finish`]) and as the core permutation of [`ZincLhaRng`].
+pub fn mix(mut x: u64) -> u64 {
+    const M: u64 = 0xbea225f9eb34556d;
+
+    x ^= x >> 32;
+    x = x.wrapping_mul(M);
+    x ^= x >> 29;
+    x = x.wrapping_mul(M);
+    x ^= x >> 32;
+    x = x.wrapping_mul(M);
+    x ^= x >> 29;
+    x
+}
+
+/// Folds a 64-byte digest down to a `u128` by XOR-ing its four 16-byte lanes.
+///
+/// Lanes are always read big-endian, so the result is identical on
+/// big- and little-endian targets regardless of the host's native
+/// byte order.
+fn fold_to_u128(digest: &[u8; 64]) -> u128 {
+    digest
+        .chunks_exact(16)
+        .map(|chunk| u128::from_be_bytes(chunk.try_into().unwrap()))
+        .fold(0u128, |acc, lane| acc ^ lane)
+}
+
+/// Hashes `bytes` with the default (unkeyed) schedule and returns the full
+/// 64-byte digest.
+pub fn hash(bytes: &[u8]) -> [u8; 64] {
+    let mut engine = ZincLha::new();
+    engine.update(bytes);
+    engine.finalize()
+}
+
+/// Hashes `bytes` with the default (unkeyed) schedule and folds the digest
+/// down to a `u64`.
+pub fn hash64(bytes: &[u8]) -> u64 {
+    fold_to_u64(&hash(bytes))
+}
+
+/// Hashes `bytes` with the default (unkeyed) schedule and folds the digest
+/// down to a `Hash128`.
+pub fn hash128(bytes: &[u8]) -> Hash128 {
+    Hash128(fold_to_u128(&hash(bytes)))
+}
+
+// --- Deterministic Keyed Stream Generator ---
+
+/// Number of `round` iterations folded over the seed block in [`ZincLhaRng::from_seed`].
+const SEED_ROUNDS: usize = 16;
+
+/// A deterministic PRNG built on the Zinc-LHA compression function.
+///
+/// Seeding runs [`init_sbox`] and a handful of [`round`]s over the seed to
+/// fill the 64-byte state; each `next_u64` call then emits the first 8 bytes
+/// of state passed through [`mix`] and advances the state by one more `round` over an incrementing counter
+/// block, so the hash doubles as a deterministic keyed stream generator.
+#[derive(Clone)]
+pub struct ZincLhaRng {
+    state: [u8; 64],
+    sbox: [u8; 256],
+    key: Vec<u8>,
+    counter: u64,
+}
+
+impl ZincLhaRng {
+    /// Advances the state by one `round` over a block built from the
+    /// current counter, then increments the counter.
+    fn advance(&mut self) {
+        let mut block = [0u8; 64];
+        block[..8].copy_from_slice(&self.counter.to_be_bytes());
+        round(&mut self.state, &block, &self.sbox, &self.key);
+        self.counter = self.counter.wrapping_add(1);
+    }
+}
+
+impl rand_core::SeedableRng for ZincLhaRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        let key = seed.to_vec();
+        let sbox = init_sbox(&key);
+
+        let mut block = [0u8; 64];
+        block[..32].copy_from_slice(&seed);
+
+        let mut state = [0u8; 64];
+        for _ in 0..SEED_ROUNDS {
+            round(&mut state, &block, &sbox, &key);
+        }
+
+        ZincLhaRng {
+            state,
+            sbox,
+            key,
+            counter: 0,
+        }
+    }
+}
+
+impl rand_core::RngCore for ZincLhaRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let out = mix(u64::from_be_bytes(self.state[..8].try_into().unwrap()));
+        self.advance();
+        out
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_be_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The salt-injection loop must be driven by the whole key, not just its
+    /// first and last bytes: with the same `sbox` and an all-zero block (so
+    /// only the salt schedule can possibly distinguish the two calls),
+    /// flipping a middle key byte while leaving the endpoints unchanged must
+    /// still change the resulting state.
+    #[test]
+    fn round_salt_schedule_uses_full_key_not_just_endpoints() {
+        let sbox = init_sbox(DEFAULT_KEY);
+        let zero_block = [0u8; 64];
+
+        let key_a = b"0123456789abcdef".to_vec();
+        let mut key_b = key_a.clone();
+        key_b[8] ^= 0xff;
+
+        let mut state_a = [0u8; 64];
+        round(&mut state_a, &zero_block, &sbox, &key_a);
+
+        let mut state_b = [0u8; 64];
+        round(&mut state_b, &zero_block, &sbox, &key_b);
+
+        assert_ne!(state_a, state_b);
+    }
+
+    /// `ZincLhaRng::from_seed` must be fully deterministic: the same seed
+    /// has to reproduce the same stream of `next_u64` outputs every time.
+    #[test]
+    fn rng_from_seed_is_deterministic() {
+        use rand_core::{RngCore, SeedableRng};
+
+        let seed = [7u8; 32];
+        let mut rng_a = ZincLhaRng::from_seed(seed);
+        let mut rng_b = ZincLhaRng::from_seed(seed);
+
+        let stream_a: Vec<u64> = (0..8).map(|_| rng_a.next_u64()).collect();
+        let stream_b: Vec<u64> = (0..8).map(|_| rng_b.next_u64()).collect();
+
+        assert_eq!(stream_a, stream_b);
+    }
+
+    /// Different seeds must diverge into different streams, and successive
+    /// outputs from the same stream must not repeat (the `counter` block
+    /// keeps advancing the state every call).
+    #[test]
+    fn rng_is_seed_sensitive_and_advances() {
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut rng_a = ZincLhaRng::from_seed([1u8; 32]);
+        let mut rng_b = ZincLhaRng::from_seed([2u8; 32]);
+
+        assert_ne!(rng_a.next_u64(), rng_b.next_u64());
+
+        let mut rng = ZincLhaRng::from_seed([3u8; 32]);
+        let first = rng.next_u64();
+        let second = rng.next_u64();
+        assert_ne!(first, second);
+    }
+
+    /// [`mix`] is meant to be an avalanche finalizer: it must be
+    /// deterministic and flipping any input bit must change the output.
+    #[test]
+    fn mix_is_deterministic_and_avalanches() {
+        let x = 0x0123_4567_89ab_cdefu64;
+        assert_eq!(mix(x), mix(x));
+
+        for bit in 0u32..64 {
+            assert_ne!(mix(x), mix(x ^ (1 << bit)));
+        }
+    }
+
+    /// Keying is a true MAC: the same message under different keys must
+    /// produce different digests. (That the key does not simply act as a
+    /// message prefix is checked by `key_is_not_merely_a_message_prefix`.)
+    #[test]
+    fn with_key_changes_digest_for_same_message() {
+        let mut a = ZincLha::<64>::with_key(b"key-a");
+        a.update(b"same message");
+        let digest_a = a.finalize();
+
+        let mut b = ZincLha::<64>::with_key(b"key-b");
+        b.update(b"same message");
+        let digest_b = b.finalize();
+
+        assert_ne!(digest_a, digest_b);
+    }
+
+    /// The key seeds the S-box and salt schedule but is kept out of the
+    /// absorbed block, so prepending the key onto the message under the
+    /// default (unkeyed) schedule must not reproduce the keyed digest.
+    #[test]
+    fn key_is_not_merely_a_message_prefix() {
+        let key = b"mac-key";
+        let message = b"mac-message";
+
+        let mut keyed = ZincLha::<64>::with_key(key);
+        keyed.update(message);
+        let keyed_digest = keyed.finalize();
+
+        let mut prefixed = ZincLha::<64>::new();
+        prefixed.update(key);
+        prefixed.update(message);
+        let prefixed_digest = prefixed.finalize();
+
+        assert_ne!(keyed_digest, prefixed_digest);
+    }
+
+    /// `Hasher::finish` must be callable repeatedly without consuming the
+    /// hasher, and must agree with folding a `finalize()`d digest of the same
+    /// bytes through [`fold_to_u64`] directly.
+    #[test]
+    fn hasher_finish_is_repeatable_and_matches_fold_to_u64() {
+        use core::hash::Hasher as _;
+
+        let mut hasher = ZincLha::<64>::new();
+        hasher.write(b"hasher integration");
+        let first = hasher.finish();
+        let second = hasher.finish();
+        assert_eq!(first, second);
+
+        let mut engine = ZincLha::<64>::new();
+        engine.update(b"hasher integration");
+        let expected = fold_to_u64(&engine.finalize());
+        assert_eq!(first, expected);
+    }
+
+    /// [`fold_to_u64`] is deterministic and reads each lane big-endian
+    /// (rather than relying on the host's native byte order), so flipping a
+    /// byte anywhere in the digest must change the folded `u64`.
+    #[test]
+    fn fold_to_u64_is_deterministic_and_sensitive_to_every_byte() {
+        let mut digest = [0u8; 64];
+        for (i, b) in digest.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+
+        assert_eq!(fold_to_u64(&digest), fold_to_u64(&digest));
+
+        for flip in 0usize..64 {
+            let mut altered = digest;
+            altered[flip] ^= 0xff;
+            assert_ne!(fold_to_u64(&digest), fold_to_u64(&altered));
+        }
+    }
+
+    /// Feeding a message through several small [`ZincLha::update`] calls must
+    /// produce the same digest as a single call with the whole message, since
+    /// `update` only buffers bytes into 64-byte blocks and shouldn't care
+    /// where the caller's chunk boundaries fall.
+    #[test]
+    fn streaming_matches_one_shot() {
+        let message = b"the quick brown fox jumps over the lazy dog, repeated \
+            enough times to span more than one 64-byte block and exercise \
+            the buffering logic in update()";
+
+        let mut one_shot = ZincLha::<64>::new();
+        one_shot.update(message);
+        let one_shot_digest = one_shot.finalize();
+
+        let mut streamed = ZincLha::<64>::new();
+        for chunk in message.chunks(7) {
+            streamed.update(chunk);
+        }
+        let streamed_digest = streamed.finalize();
+
+        assert_eq!(one_shot_digest, streamed_digest);
+    }
+
+    /// The trailing block is padded with the total message bit-length, so
+    /// messages that differ only by trailing zero bytes (and hence differ in
+    /// length) must not collide.
+    #[test]
+    fn length_padding_distinguishes_trailing_zeros() {
+        let mut short = ZincLha::<64>::new();
+        short.update(b"abc");
+        let short_digest = short.finalize();
+
+        let mut padded = ZincLha::<64>::new();
+        padded.update(b"abc\0\0\0");
+        let padded_digest = padded.finalize();
+
+        assert_ne!(short_digest, padded_digest);
+    }
+
+    /// The `N > 64` digest blocks are each domain-separated with a counter
+    /// (see [`extract_output`]), so a shorter digest must not be a prefix of
+    /// a longer one — in particular the 64-byte digest must not just be the
+    /// first 64 bytes of the 128-byte digest.
+    #[test]
+    fn longer_digest_is_not_prefixed_by_shorter_digest() {
+        let mut engine64 = ZincLha::<64>::new();
+        engine64.update(b"domain separation");
+        let digest64 = engine64.finalize();
+
+        let mut engine128 = ZincLha::<128>::new();
+        engine128.update(b"domain separation");
+        let digest128 = engine128.finalize();
+
+        assert_ne!(&digest128[..64], &digest64[..]);
+    }
+}
+
+#[cfg(all(test, feature = "simd"))]
+mod simd_tests {
+    use super::*;
+
+    #[test]
+    fn diffuse_simd_matches_scalar() {
+        let mut seed = 0x9E3779B97F4A7C15u64;
+        let mut next_byte = || {
+            seed = mix(seed);
+            seed as u8
+        };
+
+        for _ in 0..64 {
+            let mut state = [0u8; 64];
+            for b in &mut state {
+                *b = next_byte();
+            }
+
+            let mut scalar_state = state;
+            diffuse_scalar(&mut scalar_state);
+
+            let mut simd_state = state;
+            diffuse_simd(&mut simd_state);
+
+            assert_eq!(scalar_state, simd_state);
+        }
+    }
+}